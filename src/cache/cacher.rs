@@ -3,10 +3,10 @@
 
 use error_stack::Report;
 use futures::future::try_join_all;
-use md5::compute;
 use redis::{aio::ConnectionManager, AsyncCommands, Client, RedisError};
 
 use super::error::PoolError;
+use super::hash_url;
 
 /// A named struct which stores the redis Connection url address to which the client will
 /// connect to.
@@ -17,11 +17,14 @@ use super::error::PoolError;
 /// * `pool_size` - It stores the size of the connection pool (in other words the number of
 /// connections that should be stored in the pool).
 /// * `current_connection` - It stores the index of which connection is being used at the moment.
+/// * `cache_ttl` - It stores the time (in seconds) for which a cached value should be kept
+/// before it expires.
 #[derive(Clone)]
 pub struct RedisCache {
     connection_pool: Vec<ConnectionManager>,
     pool_size: u8,
     current_connection: u8,
+    cache_ttl: u64,
 }
 
 impl RedisCache {
@@ -32,9 +35,12 @@ impl RedisCache {
     /// * `redis_connection_url` - It takes the redis Connection url address.
     /// * `pool_size` - It takes the size of the connection pool (in other words the number of
     /// connections that should be stored in the pool).
+    /// * `cache_ttl` - It takes the time (in seconds) for which a cached value should be kept
+    /// before it expires.
     pub async fn new(
         redis_connection_url: &str,
         pool_size: u8,
+        cache_ttl: u64,
     ) -> Result<Self, Box<dyn std::error::Error>> {
         let client = Client::open(redis_connection_url)?;
         let mut tasks: Vec<_> = Vec::new();
@@ -47,19 +53,11 @@ impl RedisCache {
             connection_pool: try_join_all(tasks).await?,
             pool_size,
             current_connection: Default::default(),
+            cache_ttl,
         };
         Ok(redis_cache)
     }
 
-    /// A helper function which computes the hash of the url and formats and returns it as string.
-    ///
-    /// # Arguments
-    ///
-    /// * `url` - It takes an url as string.
-    fn hash_url(&self, url: &str) -> String {
-        format!("{:?}", compute(url))
-    }
-
     /// A function which fetches the cached json results as json string from the redis server.
     ///
     /// # Arguments
@@ -67,7 +65,7 @@ impl RedisCache {
     /// * `url` - It takes an url as a string.
     pub async fn cached_json(&mut self, url: &str) -> Result<String, Report<PoolError>> {
         self.current_connection = Default::default();
-        let hashed_url_string: &str = &self.hash_url(url);
+        let hashed_url_string: &str = &hash_url(url);
 
         let mut result: Result<String, RedisError> = self.connection_pool
             [self.current_connection as usize]
@@ -105,7 +103,7 @@ impl RedisCache {
 
     /// A function which caches the results by using the hashed `url` as the key and
     /// `json results` as the value and stores it in redis server with ttl(time to live)
-    /// set to 60 seconds.
+    /// set to the configured `cache_ttl`.
     ///
     /// # Arguments
     ///
@@ -117,11 +115,11 @@ impl RedisCache {
         url: &str,
     ) -> Result<(), Report<PoolError>> {
         self.current_connection = Default::default();
-        let hashed_url_string: &str = &self.hash_url(url);
+        let hashed_url_string: &str = &hash_url(url);
 
         let mut result: Result<(), RedisError> = self.connection_pool
             [self.current_connection as usize]
-            .set_ex(hashed_url_string, json_results, 60)
+            .set_ex(hashed_url_string, json_results, self.cache_ttl)
             .await;
 
         // Code to check whether the current connection being used is dropped with connection error
@@ -142,7 +140,7 @@ impl RedisCache {
                             ));
                         }
                         result = self.connection_pool[self.current_connection as usize]
-                            .set_ex(hashed_url_string, json_results, 60)
+                            .set_ex(hashed_url_string, json_results, self.cache_ttl)
                             .await;
                         continue;
                     }
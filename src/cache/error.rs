@@ -0,0 +1,36 @@
+//! This module provides the error types which are returned from the various cache backends
+//! when a value cannot be fetched or stored.
+
+use std::fmt;
+
+/// A custom error type used for handling the errors that may arise while interacting with a
+/// cache backend.
+#[derive(Debug)]
+pub enum PoolError {
+    /// This variant handles all errors related to `RedisError`.
+    #[cfg(feature = "redis-cache")]
+    RedisError(redis::RedisError),
+    /// This variant handles the error that occurs when the connection pool has been exhausted
+    /// with all the connections dropped due to a connection error.
+    PoolExhaustionWithConnectionDropError,
+    /// This variant handles the error that occurs when the requested key is missing from the
+    /// cache (i.e. it was never cached or has expired).
+    MissingValue,
+}
+
+impl fmt::Display for PoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "redis-cache")]
+            PoolError::RedisError(redis_error) => write!(f, "{redis_error}"),
+            PoolError::PoolExhaustionWithConnectionDropError => {
+                write!(f, "The redis connection pool has been exhausted")
+            }
+            PoolError::MissingValue => {
+                write!(f, "The value for the provided url is missing from the cache")
+            }
+        }
+    }
+}
+
+impl error_stack::Context for PoolError {}
@@ -0,0 +1,71 @@
+//! This module provides a two-tier cache which layers an in-memory `mini-moka` cache in front of
+//! the redis cache, so that most requests are served without a network round-trip while still
+//! sharing the cache across instances through redis.
+
+use error_stack::Report;
+
+use super::cacher::RedisCache;
+use super::error::PoolError;
+use super::memory_cache::MemoryCache;
+
+/// A named struct which holds the local, in-memory tier and the shared redis tier that together
+/// make up the hybrid cache.
+///
+/// # Fields
+///
+/// * `redis_cache` - It stores the redis cache that is shared across instances.
+/// * `memory_cache` - It stores the local, in-memory cache which is checked first.
+#[derive(Clone)]
+pub struct HybridCache {
+    redis_cache: RedisCache,
+    memory_cache: MemoryCache,
+}
+
+impl HybridCache {
+    /// Creates a new `HybridCache` from an already constructed redis cache and in-memory cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `redis_cache` - It takes the redis cache that is shared across instances.
+    /// * `memory_cache` - It takes the local, in-memory cache which is checked first.
+    pub fn new(redis_cache: RedisCache, memory_cache: MemoryCache) -> Self {
+        HybridCache {
+            redis_cache,
+            memory_cache,
+        }
+    }
+
+    /// A function which fetches the cached json results, checking the local in-memory tier
+    /// first and falling back to the redis tier on a miss, populating the in-memory tier with
+    /// the result before returning it.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - It takes an url as a string.
+    pub async fn cached_json(&mut self, url: &str) -> Result<String, Report<PoolError>> {
+        match self.memory_cache.cached_json(url).await {
+            Ok(res) => Ok(res),
+            Err(_) => {
+                let res = self.redis_cache.cached_json(url).await?;
+                self.memory_cache.cache_results(&res, url).await?;
+                Ok(res)
+            }
+        }
+    }
+
+    /// A function which caches the results by writing through to both the in-memory tier and
+    /// the redis tier.
+    ///
+    /// # Arguments
+    ///
+    /// * `json_results` - It takes the json results string as an argument.
+    /// * `url` - It takes the url as a String.
+    pub async fn cache_results(
+        &mut self,
+        json_results: &str,
+        url: &str,
+    ) -> Result<(), Report<PoolError>> {
+        self.memory_cache.cache_results(json_results, url).await?;
+        self.redis_cache.cache_results(json_results, url).await
+    }
+}
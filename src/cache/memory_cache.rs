@@ -0,0 +1,68 @@
+//! This module provides the functionality to cache the aggregated results fetched and aggregated
+//! from the upstream search engines in an in-memory cache, so that websurfx can be run without
+//! requiring a running redis server.
+
+use std::time::Duration;
+
+use error_stack::Report;
+use mini_moka::sync::Cache;
+
+use super::error::PoolError;
+use super::hash_url;
+
+/// A named struct which is used to hold the in-memory cache of the aggregated search results
+/// using `mini-moka`'s sync cache, keyed by the md5 hash of the search url.
+///
+/// # Fields
+///
+/// * `cache` - It stores the actual `mini-moka` cache object.
+#[derive(Clone)]
+pub struct MemoryCache {
+    cache: Cache<String, String>,
+}
+
+impl MemoryCache {
+    /// Creates a new `MemoryCache` with the given maximum capacity and time-to-live.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_capacity` - It takes the maximum number of entries the cache can hold at once.
+    /// * `time_to_live` - It takes the duration (in seconds) after which a cached value expires.
+    pub fn new(max_capacity: u64, time_to_live: u64) -> Self {
+        MemoryCache {
+            cache: Cache::builder()
+                .max_capacity(max_capacity)
+                .time_to_live(Duration::from_secs(time_to_live))
+                .build(),
+        }
+    }
+
+    /// A function which fetches the cached json results from the in-memory cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - It takes an url as a string.
+    pub async fn cached_json(&mut self, url: &str) -> Result<String, Report<PoolError>> {
+        let hashed_url_string: &str = &hash_url(url);
+        self.cache
+            .get(hashed_url_string)
+            .ok_or_else(|| Report::new(PoolError::MissingValue))
+    }
+
+    /// A function which caches the results by using the hashed `url` as the key and the
+    /// `json results` as the value, keeping it cached until the configured time-to-live elapses.
+    ///
+    /// # Arguments
+    ///
+    /// * `json_results` - It takes the json results string as an argument.
+    /// * `url` - It takes the url as a String.
+    pub async fn cache_results(
+        &mut self,
+        json_results: &str,
+        url: &str,
+    ) -> Result<(), Report<PoolError>> {
+        let hashed_url_string: String = hash_url(url);
+        self.cache.insert(hashed_url_string, json_results.to_owned());
+        Ok(())
+    }
+}
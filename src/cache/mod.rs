@@ -0,0 +1,89 @@
+//! This module provides the functionality to cache the aggregated results fetched and aggregated
+//! from the upstream search engines in a json format.
+
+#[cfg(feature = "redis-cache")]
+pub mod cacher;
+pub mod error;
+#[cfg(all(feature = "hybrid-cache", feature = "redis-cache", feature = "memory-cache"))]
+pub mod hybrid_cache;
+#[cfg(feature = "memory-cache")]
+pub mod memory_cache;
+
+use error_stack::Report;
+use md5::compute;
+
+use error::PoolError;
+
+#[cfg(feature = "redis-cache")]
+use cacher::RedisCache;
+#[cfg(all(feature = "hybrid-cache", feature = "redis-cache", feature = "memory-cache"))]
+use hybrid_cache::HybridCache;
+#[cfg(feature = "memory-cache")]
+use memory_cache::MemoryCache;
+
+/// A enum which provides the different caching backends that websurfx can be configured to run
+/// with, allowing the redis dependency to be swapped out for a bundled in-memory cache or a
+/// hybrid of the two.
+#[derive(Clone)]
+pub enum Cache {
+    /// Caches results in a redis server, allowing the cache to be shared across instances.
+    #[cfg(feature = "redis-cache")]
+    Redis(RedisCache),
+    /// Caches results in-memory using `mini-moka`, requiring no external dependencies.
+    #[cfg(feature = "memory-cache")]
+    InMemory(MemoryCache),
+    /// Caches results in-memory first, falling back to a shared redis server on a miss.
+    #[cfg(all(feature = "hybrid-cache", feature = "redis-cache", feature = "memory-cache"))]
+    Hybrid(HybridCache),
+}
+
+/// A helper function which computes the hash of the url and formats and returns it as string,
+/// shared by every cache backend so that the same `url` always maps to the same cache key
+/// regardless of which backend (or combination of backends) is configured.
+///
+/// # Arguments
+///
+/// * `url` - It takes an url as string.
+pub(super) fn hash_url(url: &str) -> String {
+    format!("{:?}", compute(url))
+}
+
+impl Cache {
+    /// A function which fetches the cached json results from the configured cache backend.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - It takes an url as a string.
+    pub async fn cached_json(&mut self, url: &str) -> Result<String, Report<PoolError>> {
+        match self {
+            #[cfg(feature = "redis-cache")]
+            Cache::Redis(redis_cache) => redis_cache.cached_json(url).await,
+            #[cfg(feature = "memory-cache")]
+            Cache::InMemory(memory_cache) => memory_cache.cached_json(url).await,
+            #[cfg(all(feature = "hybrid-cache", feature = "redis-cache", feature = "memory-cache"))]
+            Cache::Hybrid(hybrid_cache) => hybrid_cache.cached_json(url).await,
+        }
+    }
+
+    /// A function which caches the results by using the hashed `url` as the key and the
+    /// `json results` as the value, using whichever cache backend is configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `json_results` - It takes the json results string as an argument.
+    /// * `url` - It takes the url as a String.
+    pub async fn cache_results(
+        &mut self,
+        json_results: &str,
+        url: &str,
+    ) -> Result<(), Report<PoolError>> {
+        match self {
+            #[cfg(feature = "redis-cache")]
+            Cache::Redis(redis_cache) => redis_cache.cache_results(json_results, url).await,
+            #[cfg(feature = "memory-cache")]
+            Cache::InMemory(memory_cache) => memory_cache.cache_results(json_results, url).await,
+            #[cfg(all(feature = "hybrid-cache", feature = "redis-cache", feature = "memory-cache"))]
+            Cache::Hybrid(hybrid_cache) => hybrid_cache.cache_results(json_results, url).await,
+        }
+    }
+}
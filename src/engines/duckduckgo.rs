@@ -30,6 +30,8 @@ impl SearchEngine for DuckDuckGo {
     /// * `page` - Takes an u32 as an argument.
     /// * `user_agent` - Takes a random user agent string as an argument.
     /// * `request_timeout` - Takes a time (secs) as a value which controls the server request timeout.
+    /// * `proxy` - Takes an optional outbound proxy url to route the request through, allowing
+    /// self-hosters to avoid upstream IP blocking.
     ///
     /// # Errors
     ///
@@ -43,6 +45,7 @@ impl SearchEngine for DuckDuckGo {
         page: u32,
         user_agent: &str,
         request_timeout: u8,
+        proxy: Option<&str>,
     ) -> Result<HashMap<String, SearchResult>, EngineError> {
         // Page number can be missing or empty string and so appropriate handling is required
         // so that upstream server recieves valid page number.
@@ -73,7 +76,8 @@ impl SearchEngine for DuckDuckGo {
         .change_context(EngineError::UnexpectedError)?;
 
         let document: Html = Html::parse_document(
-            &DuckDuckGo::fetch_html_from_upstream(self, &url, header_map, request_timeout).await?,
+            &DuckDuckGo::fetch_html_from_upstream(self, &url, header_map, request_timeout, proxy)
+                .await?,
         );
 
         let no_result: Selector = Selector::parse(".no-results")
@@ -97,35 +101,29 @@ impl SearchEngine for DuckDuckGo {
             .map_err(|_| Report::new(EngineError::UnexpectedError))
             .attach_printable_lazy(|| format!("invalid CSS selector: {}", ".result__snippet"))?;
 
-        // scrape all the results from the html
+        // scrape all the results from the html, skipping any result card that is missing a
+        // title or url rather than panicking, so that occasional upstream HTML changes degrade
+        // gracefully instead of crashing the whole request.
         Ok(document
             .select(&results)
-            .map(|result| {
-                SearchResult::new(
-                    result
-                        .select(&result_title)
-                        .next()
-                        .unwrap()
-                        .inner_html()
-                        .trim(),
-                    format!(
-                        "https://{}",
-                        result
-                            .select(&result_url)
-                            .next()
-                            .unwrap()
-                            .inner_html()
-                            .trim()
-                    )
-                    .as_str(),
-                    result
-                        .select(&result_desc)
-                        .next()
-                        .unwrap()
-                        .inner_html()
-                        .trim(),
+            .enumerate()
+            .filter_map(|(rank, result)| {
+                let title = result.select(&result_title).next()?.inner_html();
+                let url = result.select(&result_url).next()?.inner_html();
+                let description = result
+                    .select(&result_desc)
+                    .next()
+                    .map(|desc| desc.inner_html())
+                    .unwrap_or_default();
+
+                let mut search_result = SearchResult::new(
+                    title.trim(),
+                    format!("https://{}", url.trim()).as_str(),
+                    description.trim(),
                     &["duckduckgo"],
-                )
+                );
+                search_result.rank = rank;
+                Some(search_result)
             })
             .map(|search_result| (search_result.url.clone(), search_result))
             .collect())
@@ -0,0 +1,273 @@
+//! This module provides the error type and the `SearchEngine` trait that every upstream search
+//! engine implements, along with the shared logic used to fetch html from those upstream
+//! engines.
+
+use std::{collections::HashMap, sync::atomic::AtomicUsize, sync::atomic::Ordering, time::Duration};
+
+use error_stack::{Result, ResultExt};
+use reqwest::header::HeaderMap;
+
+use crate::engines::duckduckgo::DuckDuckGo;
+use crate::results::aggregation_models::SearchResult;
+
+/// A custom error type used for handling the different errors that may arise while requesting
+/// and scraping results from an upstream search engine.
+#[derive(Debug)]
+pub enum EngineError {
+    /// This variant is returned when the upstream search engine returns no results for the
+    /// provided search query.
+    EmptyResultSet,
+    /// This variant handles all request related errors like timeouts, dns failures, etc.
+    RequestError,
+    /// This variant handles all other unexpected errors, for example a failure to parse a CSS
+    /// selector or construct a `HeaderMap`.
+    UnexpectedError,
+}
+
+impl std::fmt::Display for EngineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EngineError::EmptyResultSet => {
+                write!(f, "The upstream search engine returned no results for the given query")
+            }
+            EngineError::RequestError => {
+                write!(f, "Error getting the response from the upstream search engine")
+            }
+            EngineError::UnexpectedError => {
+                write!(f, "An unexpected error occurred while processing the results")
+            }
+        }
+    }
+}
+
+impl error_stack::Context for EngineError {}
+
+/// A struct which holds a list of outbound proxy urls and rotates through them round-robin so
+/// that upstream scraping requests are spread across several exit IPs.
+///
+/// # Fields
+///
+/// * `proxies` - It stores the list of proxy urls (e.g. `socks5://127.0.0.1:9050`) to rotate
+/// through.
+/// * `current_proxy` - It stores the index of the proxy that should be used for the next
+/// request.
+pub struct ProxyRotator {
+    proxies: Vec<String>,
+    current_proxy: AtomicUsize,
+}
+
+impl ProxyRotator {
+    /// Constructs a new `ProxyRotator` from the list of proxy urls configured by the operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `proxies` - It takes a list of proxy urls to rotate through.
+    pub fn new(proxies: Vec<String>) -> Self {
+        ProxyRotator {
+            proxies,
+            current_proxy: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the next proxy url to be used, rotating round-robin through the configured list,
+    /// or `None` if no proxies have been configured.
+    pub fn next(&self) -> Option<&str> {
+        if self.proxies.is_empty() {
+            return None;
+        }
+
+        let index = self.current_proxy.fetch_add(1, Ordering::Relaxed) % self.proxies.len();
+        Some(&self.proxies[index])
+    }
+}
+
+/// Queries the given engine for results, rotating to the next configured proxy (if any) before
+/// dispatching the request, so that a list of outbound proxies configured by the operator is
+/// actually spread across requests rather than built and left unused.
+///
+/// # Arguments
+///
+/// * `engine` - It takes the search engine to query for results.
+/// * `query` - It takes the user provided search query.
+/// * `page` - It takes the page number of results to fetch.
+/// * `user_agent` - It takes a random user agent string.
+/// * `request_timeout` - It takes the request timeout (secs).
+/// * `proxy_rotator` - It takes the configured `ProxyRotator`, or `None` if outbound proxying
+/// isn't enabled.
+pub async fn dispatch_results(
+    engine: &dyn SearchEngine,
+    query: &str,
+    page: u32,
+    user_agent: &str,
+    request_timeout: u8,
+    proxy_rotator: Option<&ProxyRotator>,
+) -> Result<HashMap<String, SearchResult>, EngineError> {
+    let proxy = proxy_rotator.and_then(|rotator| rotator.next());
+    engine
+        .results(query, page, user_agent, request_timeout, proxy)
+        .await
+}
+
+/// A trait to handle the different search engines and scrape results from them. Implementing
+/// this trait allows to easily create a new search engine just by implementing the `results`
+/// function.
+#[async_trait::async_trait]
+pub trait SearchEngine: Sync + Send {
+    /// This function fetches/requests the html document from the upstream search engine and
+    /// returns it as a `String`.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - It takes the url of the upstream search engine with the user's search query
+    /// appended to it as an argument.
+    /// * `header_map` - It takes the `HeaderMap` which stores all the necessary headers required
+    /// to make a request to the upstream search engine.
+    /// * `request_timeout` - It takes the request timeout (secs) as a value which controls how
+    /// long to wait for the request to complete.
+    /// * `proxy` - It takes an optional outbound proxy url which, if provided, is used to route
+    /// the request to the upstream search engine through.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `EngineError::RequestError` if the upstream search engine is unavailable, times
+    /// out, or if the provided proxy url is invalid.
+    async fn fetch_html_from_upstream(
+        &self,
+        url: &str,
+        header_map: HeaderMap,
+        request_timeout: u8,
+        proxy: Option<&str>,
+    ) -> Result<String, EngineError> {
+        let mut client_builder =
+            reqwest::Client::builder().timeout(Duration::from_secs(request_timeout as u64));
+
+        if let Some(proxy_url) = proxy {
+            client_builder = client_builder.proxy(
+                reqwest::Proxy::all(proxy_url).change_context(EngineError::RequestError)?,
+            );
+        }
+
+        client_builder
+            .build()
+            .change_context(EngineError::RequestError)?
+            .get(url)
+            .headers(header_map)
+            .send()
+            .await
+            .change_context(EngineError::RequestError)?
+            .text()
+            .await
+            .change_context(EngineError::RequestError)
+    }
+
+    /// This function scrapes results from the upstream search engine and puts all the scraped
+    /// results like title, visiting_url (href in html), engine (from which engine it was
+    /// fetched from) and description in a `SearchResult` and then adds that to a `HashMap` whose
+    /// keys are urls and values are the `SearchResult` struct and then returns it within a
+    /// `Result` enum.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Takes the user provided query to query to the upstream search engine with.
+    /// * `page` - Takes an u32 as an argument.
+    /// * `user_agent` - Takes a random user agent string as an argument.
+    /// * `request_timeout` - Takes a time (secs) as a value which controls the server request
+    /// timeout.
+    /// * `proxy` - Takes an optional outbound proxy url to route the request through.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `EngineError` if the user is not connected to the internet or if their is
+    /// failure to reach the above `upstream search engine` page or if the `upstream search
+    /// engine` is unable to provide results for the requested search query and also returns
+    /// error if the scraping selector or HeaderMap fails to initialize.
+    async fn results(
+        &self,
+        query: &str,
+        page: u32,
+        user_agent: &str,
+        request_timeout: u8,
+        proxy: Option<&str>,
+    ) -> Result<HashMap<String, SearchResult>, EngineError>;
+}
+
+/// A named struct which stores an engine's canonical name alongside the boxed `SearchEngine`
+/// implementation it resolves to, acting as a registry that maps the engine name strings
+/// arriving from user input (query parameters or cookies) to the known, constructible engines.
+///
+/// # Fields
+///
+/// * `name` - The canonical, lowercase name of the engine.
+/// * `engine` - The boxed `SearchEngine` implementation for this engine.
+pub struct EngineHandler {
+    name: &'static str,
+    engine: Box<dyn SearchEngine>,
+}
+
+impl EngineHandler {
+    /// Builds an `EngineHandler` for the engine matching the given name, returning `None` if
+    /// the name isn't one of the known engines. This lets engine names coming from untrusted
+    /// input be validated against the known set and silently dropped if unrecognized, rather
+    /// than trusted blindly.
+    ///
+    /// # Arguments
+    ///
+    /// * `engine_name` - It takes the name of the engine (case-insensitive) to look up.
+    pub fn from_name(engine_name: &str) -> Option<Self> {
+        let (name, engine): (&'static str, Box<dyn SearchEngine>) =
+            match engine_name.to_lowercase().as_str() {
+                "duckduckgo" => ("duckduckgo", Box::new(DuckDuckGo)),
+                _ => return None,
+            };
+
+        Some(EngineHandler { name, engine })
+    }
+
+    /// Returns a reference to the boxed `SearchEngine` implementation this handler resolved to,
+    /// without consuming the handler, so callers can dispatch a request against it and still
+    /// keep using the handler (e.g. to read back its `name`) afterwards.
+    pub fn engine(&self) -> &dyn SearchEngine {
+        self.engine.as_ref()
+    }
+
+    /// Consumes the `EngineHandler`, returning the engine's canonical name and the boxed
+    /// `SearchEngine` implementation it resolved to.
+    pub fn into_name_engine(self) -> (&'static str, Box<dyn SearchEngine>) {
+        (self.name, self.engine)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EngineHandler, ProxyRotator};
+
+    #[test]
+    fn from_name_resolves_a_known_engine_case_insensitively() {
+        let handler = EngineHandler::from_name("DuckDuckGo").expect("duckduckgo is known");
+        let (name, _) = handler.into_name_engine();
+        assert_eq!(name, "duckduckgo");
+    }
+
+    #[test]
+    fn from_name_drops_an_unrecognized_engine_name() {
+        assert!(EngineHandler::from_name("not-a-real-engine").is_none());
+    }
+
+    #[test]
+    fn proxy_rotator_wraps_around_past_the_end_of_the_list() {
+        let rotator = ProxyRotator::new(vec![
+            "socks5://127.0.0.1:9050".to_string(),
+            "socks5://127.0.0.1:9051".to_string(),
+        ]);
+
+        assert_eq!(rotator.next(), Some("socks5://127.0.0.1:9050"));
+        assert_eq!(rotator.next(), Some("socks5://127.0.0.1:9051"));
+        assert_eq!(rotator.next(), Some("socks5://127.0.0.1:9050"));
+    }
+
+    #[test]
+    fn proxy_rotator_returns_none_when_no_proxies_are_configured() {
+        let rotator = ProxyRotator::new(Vec::new());
+        assert_eq!(rotator.next(), None);
+    }
+}
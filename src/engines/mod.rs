@@ -0,0 +1,5 @@
+//! This module provides the `SearchEngine` trait and the individual upstream search engine
+//! implementations that scrape results from them.
+
+pub mod duckduckgo;
+pub mod engine_models;
@@ -0,0 +1,47 @@
+//! This module provides the models to store and handle the search results scraped from the
+//! upstream search engines before they are aggregated and returned to the client.
+
+/// A named struct which stores the individual result obtained from a search engine scrape,
+/// along with the list of engines that produced it once results from multiple engines have
+/// been merged together.
+///
+/// # Fields
+///
+/// * `title` - The title of the search result.
+/// * `url` - The url that the search result points to.
+/// * `description` - A short description of the search result.
+/// * `engines` - The names of the upstream search engines that returned this result.
+/// * `rank` - The 0-based position at which this result was returned by its engine, used to
+/// compute the reciprocal rank fusion score once results from multiple engines are merged.
+/// * `relevance_score` - The reciprocal rank fusion score computed once this result has been
+/// merged with the results of the other engines, used to sort the final aggregated list.
+#[derive(Debug, Clone, Default)]
+pub struct SearchResult {
+    pub title: String,
+    pub url: String,
+    pub description: String,
+    pub engines: Vec<String>,
+    pub rank: usize,
+    pub relevance_score: f64,
+}
+
+impl SearchResult {
+    /// Constructs a new `SearchResult` with the given arguments needed for the struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - It takes the title of the search result.
+    /// * `url` - It takes the url of the search result.
+    /// * `description` - It takes the description of the search result.
+    /// * `engine` - It takes a slice of engine names that produced this result.
+    pub fn new(title: &str, url: &str, description: &str, engine: &[&str]) -> Self {
+        SearchResult {
+            title: title.to_owned(),
+            url: url.to_owned(),
+            description: description.to_owned(),
+            engines: engine.iter().map(|name| name.to_string()).collect(),
+            rank: Default::default(),
+            relevance_score: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! This module provides the functionality to merge the results scraped from several upstream
+//! search engines into a single, relevance ordered list.
+
+use std::collections::HashMap;
+
+use crate::engines::engine_models::{dispatch_results, EngineHandler, ProxyRotator};
+
+use super::aggregation_models::SearchResult;
+
+/// The constant used to dampen the contribution of lower ranked results in the reciprocal rank
+/// fusion formula `1 / (k + rank)`. A higher `k` flattens the curve so that the exact rank
+/// matters less, which works well in practice for combining search engine result sets.
+const RRF_K: f64 = 60.0;
+
+/// Merges the per-engine result sets into a single list ordered by descending reciprocal rank
+/// fusion score, so that results returned by several engines (or ranked highly by a single
+/// engine) float to the top.
+///
+/// For a result appearing at (0-based) rank `r` in an engine's result set, that engine
+/// contributes `1 / (RRF_K + r)` to the result's fused score. A result returned by multiple
+/// engines accumulates the sum of each engine's contribution.
+///
+/// # Arguments
+///
+/// * `engine_results` - It takes the result set returned by each engine, keyed by url, with
+/// each `SearchResult`'s `rank` field set to its 0-based position in that engine's result set.
+pub fn reciprocal_rank_fusion(
+    engine_results: Vec<HashMap<String, SearchResult>>,
+) -> Vec<SearchResult> {
+    let mut fused_results: HashMap<String, SearchResult> = HashMap::new();
+
+    for results in engine_results {
+        for (url, result) in results {
+            let contribution = 1.0 / (RRF_K + result.rank as f64);
+
+            match fused_results.get_mut(&url) {
+                Some(existing) => {
+                    existing.relevance_score += contribution;
+                    for engine in &result.engines {
+                        if !existing.engines.contains(engine) {
+                            existing.engines.push(engine.clone());
+                        }
+                    }
+                }
+                None => {
+                    let mut result = result;
+                    result.relevance_score = contribution;
+                    fused_results.insert(url, result);
+                }
+            }
+        }
+    }
+
+    let mut fused_results: Vec<SearchResult> = fused_results.into_values().collect();
+    fused_results.sort_by(|a, b| {
+        b.relevance_score
+            .partial_cmp(&a.relevance_score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    fused_results
+}
+
+/// Queries every engine selected for this request, rotating through the configured outbound
+/// proxies between requests, and fuses the returned result sets into a single, relevance
+/// ordered list with `reciprocal_rank_fusion`. An engine that fails to return results (e.g. it
+/// times out or the upstream blocks the request) is skipped rather than failing the whole
+/// request.
+///
+/// # Arguments
+///
+/// * `query` - It takes the user provided search query.
+/// * `page` - It takes the page number of results to fetch.
+/// * `user_agent` - It takes a random user agent string.
+/// * `request_timeout` - It takes the request timeout (secs).
+/// * `engines` - It takes the engines selected for this request (see
+/// `EngineHandler::from_name`).
+/// * `proxy_rotator` - It takes the configured `ProxyRotator`, or `None` if outbound proxying
+/// isn't enabled.
+pub async fn aggregate(
+    query: &str,
+    page: u32,
+    user_agent: &str,
+    request_timeout: u8,
+    engines: &[EngineHandler],
+    proxy_rotator: Option<&ProxyRotator>,
+) -> Vec<SearchResult> {
+    let mut engine_results = Vec::with_capacity(engines.len());
+
+    for engine in engines {
+        if let Ok(results) = dispatch_results(
+            engine.engine(),
+            query,
+            page,
+            user_agent,
+            request_timeout,
+            proxy_rotator,
+        )
+        .await
+        {
+            engine_results.push(results);
+        }
+    }
+
+    reciprocal_rank_fusion(engine_results)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{reciprocal_rank_fusion, RRF_K};
+    use crate::results::aggregation_models::SearchResult;
+
+    fn result_at_rank(url: &str, engine: &str, rank: usize) -> SearchResult {
+        let mut result = SearchResult::new(url, url, url, &[engine]);
+        result.rank = rank;
+        result
+    }
+
+    #[test]
+    fn result_shared_by_two_engines_outranks_a_single_engine_result() {
+        let shared_url = "https://shared.example";
+        let single_url = "https://single.example";
+
+        let engine_one = HashMap::from([
+            (shared_url.to_string(), result_at_rank(shared_url, "alpha", 2)),
+            (single_url.to_string(), result_at_rank(single_url, "alpha", 0)),
+        ]);
+        let engine_two = HashMap::from([(
+            shared_url.to_string(),
+            result_at_rank(shared_url, "beta", 2),
+        )]);
+
+        let fused = reciprocal_rank_fusion(vec![engine_one, engine_two]);
+
+        assert_eq!(fused[0].url, shared_url);
+        assert_eq!(fused[0].engines, vec!["alpha", "beta"]);
+        assert_eq!(fused[0].relevance_score, 2.0 / (RRF_K + 2.0));
+    }
+
+    #[test]
+    fn single_engine_result_passes_through_with_its_own_contribution() {
+        let url = "https://only.example";
+        let engine_results = vec![HashMap::from([(
+            url.to_string(),
+            result_at_rank(url, "alpha", 3),
+        )])];
+
+        let fused = reciprocal_rank_fusion(engine_results);
+
+        assert_eq!(fused.len(), 1);
+        assert_eq!(fused[0].url, url);
+        assert_eq!(fused[0].relevance_score, 1.0 / (RRF_K + 3.0));
+    }
+
+    #[test]
+    fn empty_input_produces_no_results() {
+        assert!(reciprocal_rank_fusion(Vec::new()).is_empty());
+    }
+}
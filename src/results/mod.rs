@@ -0,0 +1,5 @@
+//! This module provides the models used to store and aggregate the search results scraped from
+//! the upstream search engines.
+
+pub mod aggregation_models;
+pub mod aggregator;